@@ -0,0 +1,285 @@
+//! Async counterpart to [`Throbber`](crate::Throbber).
+//!
+//! Requires the `async` feature.
+
+use std::future::Future;
+use std::io::{self, Write};
+use std::time::Duration;
+
+use futures::channel::mpsc::{self, UnboundedReceiver, UnboundedSender};
+use futures::future::Either;
+use futures::{select, FutureExt, StreamExt};
+
+use crate::{Frames, DEFAULT_F};
+
+/// An async counterpart to [`Throbber`](crate::Throbber).
+///
+/// Instead of spawning a `std::thread`, [`spawn_on`](AsyncThrobber::spawn_on) builds the animation as a future that the caller spawns on their own executor (e.g. `smol::spawn` or `tokio::spawn`), so users who already run an async runtime don't pay for an extra OS thread per spinner. Aside from that, its setters and its `start`/`finish`/`success`/`fail` methods mirror those of [`Throbber`](crate::Throbber); there is no guard, `take_error` or `try_*` counterpart.
+pub struct AsyncThrobber {
+    anim: Option<AsyncThrobberAnim>,
+    message: String,
+    interval: Duration,
+    frames: Frames,
+}
+
+struct AsyncThrobberAnim {
+    sender: UnboundedSender<AsyncThrobberSignal>,
+}
+
+enum AsyncThrobberSignal {
+    Start,
+    Finish,
+    Succ(String),
+    Fail(String),
+    ChMsg(String),
+    ChInt(Duration),
+    ChFrames(Frames),
+    End,
+}
+
+impl Default for AsyncThrobber {
+    /// # Default Values
+    ///
+    /// - message: `""`
+    /// - interval: `Duration::from_millis(200)`
+    /// - frames: `DEFAULT_F (⠋   ⠙   ⠹   ⠸   ⠼   ⠴   ⠦   ⠧   ⠇   ⠏)`
+    fn default() -> Self {
+        Self {
+            anim: None,
+            message: "".to_owned(),
+            interval: Duration::from_millis(200),
+            frames: (&DEFAULT_F).into(),
+        }
+    }
+}
+
+impl Drop for AsyncThrobber {
+    fn drop(&mut self) {
+        if let Some(anim) = self.anim.take() {
+            let _ = anim.sender.unbounded_send(AsyncThrobberSignal::End);
+        }
+    }
+}
+
+impl AsyncThrobber {
+    /// Creates a new AsyncThrobber object.
+    pub fn new<S: Into<String>>(
+        message: S,
+        interval: Duration,
+        frames: impl Into<Frames>,
+    ) -> Self {
+        Self {
+            anim: None,
+            message: message.into(),
+            interval,
+            frames: frames.into(),
+        }
+    }
+
+    /// Sets the message displayed next to the throbber.
+    pub fn message<S: Into<String>>(mut self, msg: S) -> Self {
+        self.set_message(msg);
+        self
+    }
+
+    /// Sets the message displayed next to the throbber.
+    pub fn set_message<S: Into<String>>(&mut self, msg: S) {
+        self.message = msg.into();
+        if let Some(ref anim) = self.anim {
+            let _ = anim
+                .sender
+                .unbounded_send(AsyncThrobberSignal::ChMsg(self.message.clone()));
+        }
+    }
+
+    /// Sets the animation frame interval, i.e. the time between frames.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.set_interval(interval);
+        self
+    }
+
+    /// Sets the animation frame interval, i.e. the time between frames.
+    pub fn set_interval(&mut self, interval: Duration) {
+        self.interval = interval;
+        if let Some(ref anim) = self.anim {
+            let _ = anim
+                .sender
+                .unbounded_send(AsyncThrobberSignal::ChInt(self.interval));
+        }
+    }
+
+    /// Sets the animation frames.
+    ///
+    /// Accepts the `&'static` constants (e.g. [`DEFAULT_F`](crate::DEFAULT_F)) as well as any owned [`Frames`], such as one built at runtime or looked up via [`Frames::by_name`].
+    pub fn frames(mut self, frames: impl Into<Frames>) -> Self {
+        self.set_frames(frames);
+        self
+    }
+
+    /// Sets the animation frames.
+    ///
+    /// Accepts the `&'static` constants (e.g. [`DEFAULT_F`](crate::DEFAULT_F)) as well as any owned [`Frames`], such as one built at runtime or looked up via [`Frames::by_name`].
+    pub fn set_frames(&mut self, frames: impl Into<Frames>) {
+        self.frames = frames.into();
+        if let Some(ref anim) = self.anim {
+            let _ = anim
+                .sender
+                .unbounded_send(AsyncThrobberSignal::ChFrames(self.frames.clone()));
+        }
+    }
+
+    /// Builds the animation task for the current message, interval and frames.
+    ///
+    /// This does not spawn anything by itself: the returned future must be handed to the caller's executor (e.g. `smol::spawn(throbber.spawn_on())` or `tokio::spawn(throbber.spawn_on())`) to actually drive the animation. Can only be called once per `AsyncThrobber`; subsequent calls are a no-op and return a future that resolves immediately.
+    pub fn spawn_on(&mut self) -> impl Future<Output = ()> {
+        if self.anim.is_some() {
+            return Either::Right(async {});
+        }
+
+        let (sender, receiver) = mpsc::unbounded();
+
+        let msg = self.message.clone();
+        let interval = self.interval;
+        let frames = self.frames.clone();
+
+        self.anim = Some(AsyncThrobberAnim { sender });
+
+        Either::Left(animation_task(receiver, msg, interval, frames))
+    }
+
+    /// Starts the animation.
+    pub fn start(&mut self) {
+        if let Some(ref anim) = self.anim {
+            let _ = anim.sender.unbounded_send(AsyncThrobberSignal::Start);
+        }
+    }
+
+    /// Starts the animation with the specified `msg`.
+    ///
+    /// Equivalent to `throbber.set_message(msg); throbber.start();`.
+    pub fn start_with_msg<S: Into<String>>(&mut self, msg: S) {
+        self.set_message(msg);
+        self.start();
+    }
+
+    /// Stops the current animation, leaving a blank line.
+    pub fn finish(&mut self) {
+        if let Some(ref anim) = self.anim {
+            let _ = anim.sender.unbounded_send(AsyncThrobberSignal::Finish);
+        }
+    }
+
+    /// Stops the current animation and prints `msg` as a *success message* (`✔`).
+    pub fn success<S: Into<String> + std::fmt::Display>(&mut self, msg: S) {
+        if let Some(ref anim) = self.anim {
+            let _ = anim
+                .sender
+                .unbounded_send(AsyncThrobberSignal::Succ(msg.into()));
+        } else {
+            println!("\x1B[2K\r✔ {}", msg);
+        }
+    }
+
+    /// Stops the current animation and prints `msg` as a *fail message* (`✖`).
+    ///
+    /// This still prints to stdout, *not* stderr.
+    pub fn fail<S: Into<String> + std::fmt::Display>(&mut self, msg: S) {
+        if let Some(ref anim) = self.anim {
+            let _ = anim
+                .sender
+                .unbounded_send(AsyncThrobberSignal::Fail(msg.into()));
+        } else {
+            println!("\x1B[2K\r✖ {}", msg);
+        }
+    }
+}
+
+/// Writes `line` to stdout and flushes it, offloaded to the blocking thread pool
+/// (via the `blocking` crate) so the calling task never blocks the executor on it.
+async fn write_and_flush(line: String) -> io::Result<()> {
+    blocking::unblock(move || {
+        let mut stdout = io::stdout();
+        stdout.write_all(line.as_bytes())?;
+        stdout.flush()
+    })
+    .await
+}
+
+async fn animation_task(
+    mut receiver: UnboundedReceiver<AsyncThrobberSignal>,
+    mut msg: String,
+    mut interval: Duration,
+    mut frames: Frames,
+) {
+    let mut play_anim = true;
+    let mut frame = 0;
+
+    loop {
+        enum Event {
+            Signal(AsyncThrobberSignal),
+            Disconnected,
+            Timeout,
+        }
+
+        let event = if play_anim {
+            select! {
+                signal = receiver.next() => match signal {
+                    Some(signal) => Event::Signal(signal),
+                    None => Event::Disconnected,
+                },
+                _ = FutureExt::fuse(async_io::Timer::after(interval)) => Event::Timeout,
+            }
+        } else {
+            match receiver.next().await {
+                Some(signal) => Event::Signal(signal),
+                None => Event::Disconnected,
+            }
+        };
+
+        match event {
+            Event::Timeout => {
+                if write_and_flush(format!("\x1B[2K\r{} {}", &frames[frame], msg))
+                    .await
+                    .is_err()
+                {
+                    play_anim = false;
+                }
+                frame = (frame + 1) % frames.len();
+                continue;
+            }
+            Event::Disconnected => {
+                let _ = write_and_flush("\x1B[2K\r".to_owned()).await;
+                break;
+            }
+            Event::Signal(AsyncThrobberSignal::Start) => {
+                play_anim = true;
+            }
+            Event::Signal(AsyncThrobberSignal::Finish) => {
+                let _ = write_and_flush("\x1B[2K\r".to_owned()).await;
+                play_anim = false;
+            }
+            Event::Signal(AsyncThrobberSignal::Succ(succ_msg)) => {
+                let _ = write_and_flush(format!("\x1B[2K\r✔ {}\n", succ_msg)).await;
+                play_anim = false;
+            }
+            Event::Signal(AsyncThrobberSignal::Fail(fail_msg)) => {
+                let _ = write_and_flush(format!("\x1B[2K\r✖ {}\n", fail_msg)).await;
+                play_anim = false;
+            }
+            Event::Signal(AsyncThrobberSignal::ChMsg(new_msg)) => {
+                msg = new_msg;
+            }
+            Event::Signal(AsyncThrobberSignal::ChInt(new_dur)) => {
+                interval = new_dur;
+            }
+            Event::Signal(AsyncThrobberSignal::ChFrames(new_frames)) => {
+                frames = new_frames;
+                frame = 0;
+            }
+            Event::Signal(AsyncThrobberSignal::End) => {
+                let _ = write_and_flush("\x1B[2K\r".to_owned()).await;
+                break;
+            }
+        }
+    }
+}
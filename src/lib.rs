@@ -33,6 +33,8 @@
 //!                                    // see the Constants section
 //! ```
 //!
+//! [`frames`](Throbber::frames)/[`set_frames`] also accept a runtime-built [`Frames`], e.g. from [`Frames::by_name`] or collected from any iterator of strings, so animations don't have to be known at compile time.
+//!
 //! Then you can simply call [`start`] wherever you want to start the animation and a _finish function_ like [`success`] where you want to stop it.
 //!
 //! ```rust
@@ -46,9 +48,25 @@
 //! After, you can call [`start`] or [`start_with_msg`] again to start the animation again.
 //! Setters are also provided, e.g. [`set_message`] and [`set_frames`]. This also works while an animation is running.
 //!
+//! If the work between [`start`] and the finish function can return early or panic, use [`start_guard`] instead: it returns a [`ThrobberGuard`] that stops the animation on drop, so it can't be left spinning.
+//!
+//! ```rust
+//! # use throbber::Throbber;
+//! # let mut throbber = Throbber::default();
+//! # fn calculate() -> Result<(), ()> { Ok(()) }
+//! let guard = throbber.start_guard();
+//! calculate()?;
+//! guard.success("calculations successful!");
+//! # Ok::<(), ()>(())
+//! ```
+//!
+//! ## Async
+//!
+//! With the `async` feature enabled, [`AsyncThrobber`] offers the same setters and start/finish/success/fail methods but drives the animation as a future on your own executor (e.g. `smol` or `tokio`) instead of spawning a dedicated OS thread. Build the task with [`spawn_on`](AsyncThrobber::spawn_on) and hand it to your executor's spawn function.
+//!
 //! ## Thread Lifetime
 //!
-//! The Throbber thread gets spawned on the first call to [`start`] or [`start_with_msg`]. After that, the thread only ever gets parked.
+//! The Throbber thread gets spawned on the first call to [`start`] or [`start_with_msg`]. After that, the thread just blocks waiting for the next signal or frame deadline.
 //! If you want to end the thread, you must drop the Throbber object:
 //!
 //! ```rust
@@ -74,14 +92,23 @@
 //! [`Throbber`]: Throbber
 //! [`start`]: Throbber::start
 //! [`start_with_msg`]: Throbber::start_with_msg
+//! [`start_guard`]: Throbber::start_guard
 //! [`set_message`]: Throbber::set_message
 //! [`set_frames`]: Throbber::set_frames
 //! [`success`]: Throbber::success
+//! [`ThrobberGuard`]: ThrobberGuard
+//! [`Frames`]: Frames
 
-use std::io::Write;
-use std::sync::mpsc::{self, Receiver, Sender, TryRecvError};
+use std::io::{self, Write};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
 use std::thread::{self, JoinHandle};
-use std::time::Duration;
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "async")]
+mod async_throbber;
+#[cfg(feature = "async")]
+pub use async_throbber::AsyncThrobber;
 
 /// `⠋   ⠙   ⠹   ⠸   ⠼   ⠴   ⠦   ⠧   ⠇   ⠏`
 pub const DEFAULT_F: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
@@ -103,10 +130,110 @@ pub const MOVE_MIN_LONG_F: [&str; 10] = [
     "[-    ]", "[--   ]", "[ --  ]", "[  -- ]", "[   --]", "[    -]", "[   --]", "[  -- ]",
     "[ --  ]", "[--   ]",
 ];
+/// `⠈⠁   ⠈⠑   ⠈⠱   ⠈⠳   ⠈⠻   ⠈⠿   ⠀⠿   ⠀⠷   ⠀⠧   ⠀⠇   ⠀⠃   ⠀⠉`
+pub const DOTS12_F: [&str; 12] = [
+    "⠈⠁", "⠈⠑", "⠈⠱", "⠈⠳", "⠈⠻", "⠈⠿", "⠀⠿", "⠀⠷", "⠀⠧", "⠀⠇", "⠀⠃", "⠀⠉",
+];
+
+/// An owned, runtime-buildable set of animation frames.
+///
+/// Unlike the `&'static [&'static str]` constants (e.g. [`DEFAULT_F`]), a `Frames` can be built at runtime from a [`Vec<String>`], collected from any iterator of strings, or looked up by name from a small built-in catalog via [`Frames::by_name`]. [`Throbber::frames`] and [`Throbber::set_frames`] accept `impl Into<Frames>`, so both the `&'static` constants and these dynamic sequences work.
+///
+/// The `&'static` constants are kept as a plain slice internally, so passing one of them doesn't allocate; anything built at runtime is stored as an `Arc<[String]>`, so cloning it (e.g. to hand a copy to the animation thread) is still cheap.
+///
+/// # Panics
+///
+/// The conversions panic if given zero frames, since the animation thread would otherwise have nothing to index into.
+#[derive(Clone)]
+pub struct Frames(FramesRepr);
+
+#[derive(Clone)]
+enum FramesRepr {
+    Static(&'static [&'static str]),
+    Owned(Arc<[String]>),
+}
+
+impl Frames {
+    /// Looks up one of the built-in frame sets by name: `"default"`, `"circle"`, `"rotate"`, `"move_eq"`, `"move_min"`, `"move_eq_long"`, `"move_min_long"` or `"dots12"`.
+    ///
+    /// Returns `None` if `name` doesn't match any built-in set.
+    pub fn by_name(name: &str) -> Option<Frames> {
+        let frames: &'static [&'static str] = match name {
+            "default" => &DEFAULT_F,
+            "circle" => &CIRCLE_F,
+            "rotate" => &ROTATE_F,
+            "move_eq" => &MOVE_EQ_F,
+            "move_min" => &MOVE_MIN_F,
+            "move_eq_long" => &MOVE_EQ_LONG_F,
+            "move_min_long" => &MOVE_MIN_LONG_F,
+            "dots12" => &DOTS12_F,
+            _ => return None,
+        };
+        Some(frames.into())
+    }
+
+    /// The number of frames in this set.
+    pub fn len(&self) -> usize {
+        match &self.0 {
+            FramesRepr::Static(frames) => frames.len(),
+            FramesRepr::Owned(frames) => frames.len(),
+        }
+    }
+
+    /// Whether this set has no frames.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn from_static(frames: &'static [&'static str]) -> Self {
+        assert!(!frames.is_empty(), "Frames must contain at least one frame");
+        Frames(FramesRepr::Static(frames))
+    }
+
+    fn from_owned(frames: Arc<[String]>) -> Self {
+        assert!(!frames.is_empty(), "Frames must contain at least one frame");
+        Frames(FramesRepr::Owned(frames))
+    }
+}
+
+impl std::ops::Index<usize> for Frames {
+    type Output = str;
+
+    fn index(&self, i: usize) -> &str {
+        match &self.0 {
+            FramesRepr::Static(frames) => frames[i],
+            FramesRepr::Owned(frames) => &frames[i],
+        }
+    }
+}
+
+impl From<&'static [&'static str]> for Frames {
+    fn from(frames: &'static [&'static str]) -> Self {
+        Frames::from_static(frames)
+    }
+}
+
+impl<const N: usize> From<&'static [&'static str; N]> for Frames {
+    fn from(frames: &'static [&'static str; N]) -> Self {
+        frames.as_slice().into()
+    }
+}
+
+impl From<Vec<String>> for Frames {
+    fn from(frames: Vec<String>) -> Self {
+        Frames::from_owned(frames.into())
+    }
+}
+
+impl<S: Into<String>> FromIterator<S> for Frames {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        Frames::from_owned(iter.into_iter().map(Into::into).collect())
+    }
+}
 
 /// Representation of a throbber animation. It can start, succeed, fail or finish at any point.
 ///
-/// Note that the Throbber thread gets spawned on the first call to [`start`](Throbber::start) or [`start_with_msg`](Throbber::start_with_msg). After that, the thread only ever gets parked.
+/// Note that the Throbber thread gets spawned on the first call to [`start`](Throbber::start) or [`start_with_msg`](Throbber::start_with_msg). After that, the thread just blocks waiting for the next signal or frame deadline.
 /// If you want to end the thread, you must drop the Throbber object.
 ///
 /// # Examples
@@ -133,12 +260,13 @@ pub struct Throbber {
     anim: Option<ThrobberAnim>,
     message: String,
     interval: Duration,
-    frames: &'static [&'static str],
+    frames: Frames,
 }
 
 struct ThrobberAnim {
     thread: JoinHandle<()>,
     sender: Sender<ThrobberSignal>,
+    error: Arc<Mutex<Option<io::Error>>>,
 }
 
 enum ThrobberSignal {
@@ -148,7 +276,7 @@ enum ThrobberSignal {
     Fail(String),
     ChMsg(String),
     ChInt(Duration),
-    ChFrames(&'static [&'static str]),
+    ChFrames(Frames),
     End,
 }
 
@@ -163,7 +291,7 @@ impl Default for Throbber {
             anim: None,
             message: "".to_owned(),
             interval: Duration::from_millis(200),
-            frames: &DEFAULT_F,
+            frames: (&DEFAULT_F).into(),
         }
     }
 }
@@ -171,9 +299,10 @@ impl Default for Throbber {
 impl Drop for Throbber {
     fn drop(&mut self) {
         if let Some(anim) = self.anim.take() {
-            anim.sender.send(ThrobberSignal::End).unwrap();
-            anim.thread.thread().unpark();
-            anim.thread.join().unwrap();
+            // swallow rather than panic: a closed stdout or a dead worker
+            // thread shouldn't bring down whatever is unwinding right now
+            let _ = anim.sender.send(ThrobberSignal::End);
+            let _ = anim.thread.join();
         }
     }
 }
@@ -183,13 +312,13 @@ impl Throbber {
     pub fn new<S: Into<String>>(
         message: S,
         interval: Duration,
-        frames: &'static [&'static str],
+        frames: impl Into<Frames>,
     ) -> Self {
         Self {
             anim: None,
             message: message.into(),
             interval,
-            frames,
+            frames: frames.into(),
         }
     }
 
@@ -203,10 +332,10 @@ impl Throbber {
     pub fn set_message<S: Into<String>>(&mut self, msg: S) {
         self.message = msg.into();
         if let Some(ref anim) = self.anim {
-            anim.sender
-                .send(ThrobberSignal::ChMsg(self.message.clone()))
-                .unwrap();
-            anim.thread.thread().unpark();
+            // swallow rather than panic: see Drop for Throbber
+            let _ = anim
+                .sender
+                .send(ThrobberSignal::ChMsg(self.message.clone()));
         }
     }
 
@@ -220,49 +349,58 @@ impl Throbber {
     pub fn set_interval<D: Into<Duration>>(&mut self, interval: D) {
         self.interval = interval.into();
         if let Some(ref anim) = self.anim {
-            anim.sender
-                .send(ThrobberSignal::ChInt(self.interval))
-                .unwrap();
-            anim.thread.thread().unpark();
+            // swallow rather than panic: see Drop for Throbber
+            let _ = anim.sender.send(ThrobberSignal::ChInt(self.interval));
         }
     }
 
     /// Sets the animation frames.
-    pub fn frames(mut self, frames: &'static [&'static str]) -> Self {
+    ///
+    /// Accepts the `&'static` constants (e.g. [`DEFAULT_F`]) as well as any owned [`Frames`], such as one built at runtime or looked up via [`Frames::by_name`].
+    pub fn frames(mut self, frames: impl Into<Frames>) -> Self {
         self.set_frames(frames);
         self
     }
 
     /// Sets the animation frames.
-    pub fn set_frames(&mut self, frames: &'static [&'static str]) {
+    ///
+    /// Accepts the `&'static` constants (e.g. [`DEFAULT_F`]) as well as any owned [`Frames`], such as one built at runtime or looked up via [`Frames::by_name`].
+    pub fn set_frames(&mut self, frames: impl Into<Frames>) {
         self.frames = frames.into();
         if let Some(ref anim) = self.anim {
-            anim.sender
-                .send(ThrobberSignal::ChFrames(self.frames))
-                .unwrap();
-            anim.thread.thread().unpark();
+            // swallow rather than panic: see Drop for Throbber
+            let _ = anim
+                .sender
+                .send(ThrobberSignal::ChFrames(self.frames.clone()));
         }
     }
 
     /// Starts the animation.
     ///
-    /// If this is the first call to [`start`](Throbber::start), a new thread gets created to play the animation. Otherwise the thread that already exists gets unparked and starts the animation again.
+    /// If this is the first call to [`start`](Throbber::start), a new thread gets created to play the animation. Otherwise the thread that already exists is signalled and starts the animation again.
     pub fn start(&mut self) {
         if let Some(ref anim) = self.anim {
-            anim.sender.send(ThrobberSignal::Start).unwrap();
-            anim.thread.thread().unpark();
+            // swallow rather than panic: see Drop for Throbber
+            let _ = anim.sender.send(ThrobberSignal::Start);
             return;
         }
 
         let (sender, receiver): (Sender<ThrobberSignal>, Receiver<ThrobberSignal>) =
             mpsc::channel();
+        let error = Arc::new(Mutex::new(None));
 
         let msg = self.message.clone();
         let interval = self.interval;
-        let frames = self.frames;
-        let thread = thread::spawn(move || animation_thread(receiver, msg, interval, frames));
+        let frames = self.frames.clone();
+        let thread_error = Arc::clone(&error);
+        let thread =
+            thread::spawn(move || animation_thread(receiver, msg, interval, frames, thread_error));
 
-        self.anim = Some(ThrobberAnim { thread, sender });
+        self.anim = Some(ThrobberAnim {
+            thread,
+            sender,
+            error,
+        });
     }
 
     /// Starts the animation with the specified `msg`.
@@ -273,19 +411,38 @@ impl Throbber {
         self.start();
     }
 
+    /// Starts the animation and returns a [`ThrobberGuard`] that automatically calls [`finish`](Throbber::finish) when dropped, unless [`success`](ThrobberGuard::success) or [`fail`](ThrobberGuard::fail) was called on it first.
+    ///
+    /// This turns the common `throbber.start(); /* work that may early-return */ throbber.success(...)` pattern into a leak-proof scoped form, so the animation can't be left spinning if the enclosing function returns early or a panic unwinds through it.
+    pub fn start_guard(&mut self) -> ThrobberGuard<'_> {
+        self.start();
+        ThrobberGuard {
+            throbber: self,
+            done: false,
+        }
+    }
+
+    /// Starts the animation with the specified `msg` and returns a [`ThrobberGuard`].
+    ///
+    /// Equivalent to `throbber.set_message(msg); throbber.start_guard()`.
+    pub fn start_guard_with_msg<S: Into<String>>(&mut self, msg: S) -> ThrobberGuard<'_> {
+        self.set_message(msg);
+        self.start_guard()
+    }
+
     /// Stops the current animation, leaving a blank line.
     pub fn finish(&mut self) {
         if let Some(ref anim) = self.anim {
-            anim.sender.send(ThrobberSignal::Finish).unwrap();
-            anim.thread.thread().unpark();
+            // swallow rather than panic: see Drop for Throbber
+            let _ = anim.sender.send(ThrobberSignal::Finish);
         }
     }
 
     /// Stops the current animation and prints `msg` as a *success message* (`✔`).
-    pub fn success<'a, S: Into<String> + std::fmt::Display>(&mut self, msg: S) {
+    pub fn success<S: Into<String> + std::fmt::Display>(&mut self, msg: S) {
         if let Some(ref anim) = self.anim {
-            anim.sender.send(ThrobberSignal::Succ(msg.into())).unwrap();
-            anim.thread.thread().unpark();
+            // swallow rather than panic: see Drop for Throbber
+            let _ = anim.sender.send(ThrobberSignal::Succ(msg.into()));
         } else {
             println!("\x1B[2K\r✔ {}", msg);
         }
@@ -294,81 +451,290 @@ impl Throbber {
     /// Stops the current animation and prints `msg` as a *fail message* (`✖`).
     ///
     /// This still prints to stdout, *not* stderr.
-    pub fn fail<'a, S: Into<String>>(&mut self, msg: S) {
+    pub fn fail<S: Into<String>>(&mut self, msg: S) {
         let msg = msg.into();
         if let Some(ref anim) = self.anim {
-            anim.sender.send(ThrobberSignal::Fail(msg)).unwrap();
-            anim.thread.thread().unpark();
+            // swallow rather than panic: see Drop for Throbber
+            let _ = anim.sender.send(ThrobberSignal::Fail(msg));
         } else {
             println!("\x1B[2K\r✖ {}", msg);
         }
     }
+
+    /// Takes the first [`io::Error`](std::io::Error) the worker thread hit while writing to stdout (e.g. a closed or redirected terminal), if any.
+    ///
+    /// The worker stashes at most one error and stops rendering once it hits one; calling this clears it, so a later write failure can be observed too.
+    pub fn take_error(&mut self) -> Option<io::Error> {
+        self.anim
+            .as_ref()
+            .and_then(|anim| anim.error.lock().unwrap().take())
+    }
+
+    /// Like [`success`](Throbber::success), but returns any stashed write error instead of ignoring it, and reports a dead worker thread as an error instead of silently doing nothing.
+    pub fn try_success<S: Into<String> + std::fmt::Display>(&mut self, msg: S) -> io::Result<()> {
+        if let Some(err) = self.take_error() {
+            return Err(err);
+        }
+        if let Some(ref anim) = self.anim {
+            anim.sender
+                .send(ThrobberSignal::Succ(msg.into()))
+                .map_err(|_| io::Error::other("throbber worker thread is gone"))?;
+        } else {
+            println!("\x1B[2K\r✔ {}", msg);
+        }
+        Ok(())
+    }
+
+    /// Like [`finish`](Throbber::finish), but returns any stashed write error instead of ignoring it, and reports a dead worker thread as an error instead of silently doing nothing.
+    pub fn try_finish(&mut self) -> io::Result<()> {
+        if let Some(err) = self.take_error() {
+            return Err(err);
+        }
+        if let Some(ref anim) = self.anim {
+            anim.sender
+                .send(ThrobberSignal::Finish)
+                .map_err(|_| io::Error::other("throbber worker thread is gone"))?;
+        }
+        Ok(())
+    }
+}
+
+/// An RAII guard returned by [`start_guard`](Throbber::start_guard) that automatically stops the animation when it goes out of scope.
+///
+/// If the guard is dropped without calling [`success`](ThrobberGuard::success) or [`fail`](ThrobberGuard::fail), it calls [`finish`](Throbber::finish) instead, the same as an early return or a panic unwinding through the scope.
+pub struct ThrobberGuard<'a> {
+    throbber: &'a mut Throbber,
+    done: bool,
+}
+
+impl<'a> ThrobberGuard<'a> {
+    /// Stops the animation and prints `msg` as a *success message* (`✔`).
+    pub fn success<S: Into<String> + std::fmt::Display>(mut self, msg: S) {
+        self.throbber.success(msg);
+        self.done = true;
+    }
+
+    /// Stops the animation and prints `msg` as a *fail message* (`✖`).
+    ///
+    /// This still prints to stdout, *not* stderr.
+    pub fn fail<S: Into<String>>(mut self, msg: S) {
+        self.throbber.fail(msg);
+        self.done = true;
+    }
+}
+
+impl<'a> Drop for ThrobberGuard<'a> {
+    fn drop(&mut self) {
+        if !self.done {
+            self.throbber.finish();
+        }
+    }
 }
 
-fn animation_thread<'a>(
+/// Stores `err` in `slot` only if it's empty, so the first error wins and
+/// later ones don't overwrite it before [`Throbber::take_error`] observes it.
+fn stash_first_error(slot: &Mutex<Option<io::Error>>, err: io::Error) {
+    let mut slot = slot.lock().unwrap();
+    if slot.is_none() {
+        *slot = Some(err);
+    }
+}
+
+fn animation_thread(
     receiver: Receiver<ThrobberSignal>,
     mut msg: String,
     mut interval: Duration,
-    mut frames: &'static [&'static str],
+    mut frames: Frames,
+    error: Arc<Mutex<Option<io::Error>>>,
 ) {
     let mut play_anim = true;
     let mut frame = 0;
+    let mut deadline = Instant::now() + interval;
+
+    // stashes the first write error and stops rendering so a closed or
+    // redirected stdout degrades gracefully instead of panicking the thread
+    let record = |result: io::Result<()>, play_anim: &mut bool| {
+        if let Err(e) = result {
+            stash_first_error(&error, e);
+            *play_anim = false;
+        }
+    };
+
     loop {
-        match receiver.try_recv() {
-            Ok(ThrobberSignal::Start) => {
+        // while animating, block only until the next frame is due so that a
+        // signal sent mid-interval is handled right away instead of waiting
+        // out the rest of the sleep; while paused, block indefinitely
+        let signal = if play_anim {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            match receiver.recv_timeout(remaining) {
+                Ok(signal) => signal,
+                Err(RecvTimeoutError::Timeout) => {
+                    let mut stdout = io::stdout();
+                    record(write!(stdout, "\x1B[2K\r{} {}", &frames[frame], msg), &mut play_anim);
+                    record(stdout.flush(), &mut play_anim);
+                    frame = (frame + 1) % frames.len();
+                    deadline += interval;
+                    continue;
+                }
+                Err(RecvTimeoutError::Disconnected) => {
+                    let mut stdout = io::stdout();
+                    record(write!(stdout, "\x1B[2K\r"), &mut play_anim);
+                    record(stdout.flush(), &mut play_anim);
+                    break;
+                }
+            }
+        } else {
+            match receiver.recv() {
+                Ok(signal) => signal,
+                Err(_) => {
+                    let mut stdout = io::stdout();
+                    record(write!(stdout, "\x1B[2K\r"), &mut play_anim);
+                    record(stdout.flush(), &mut play_anim);
+                    break;
+                }
+            }
+        };
+
+        match signal {
+            ThrobberSignal::Start => {
                 play_anim = true;
-                continue;
+                deadline = Instant::now() + interval;
             }
-            Ok(ThrobberSignal::Finish) => {
-                print!("\x1B[2K\r");
-                std::io::stdout().flush().unwrap();
+            ThrobberSignal::Finish => {
+                let mut stdout = io::stdout();
+                record(write!(stdout, "\x1B[2K\r"), &mut play_anim);
+                record(stdout.flush(), &mut play_anim);
                 play_anim = false;
-                continue;
             }
-            Ok(ThrobberSignal::Succ(succ_msg)) => {
-                println!("\x1B[2K\r✔ {}", succ_msg);
+            ThrobberSignal::Succ(succ_msg) => {
+                record(
+                    writeln!(io::stdout(), "\x1B[2K\r✔ {}", succ_msg),
+                    &mut play_anim,
+                );
                 play_anim = false;
-                continue;
             }
-            Ok(ThrobberSignal::Fail(fail_msg)) => {
-                println!("\x1B[2K\r✖ {}", fail_msg);
+            ThrobberSignal::Fail(fail_msg) => {
+                record(
+                    writeln!(io::stdout(), "\x1B[2K\r✖ {}", fail_msg),
+                    &mut play_anim,
+                );
                 play_anim = false;
-                continue;
             }
-            Ok(ThrobberSignal::ChMsg(new_msg)) => {
+            ThrobberSignal::ChMsg(new_msg) => {
                 msg = new_msg;
-                continue;
             }
-            Ok(ThrobberSignal::ChInt(new_dur)) => {
+            ThrobberSignal::ChInt(new_dur) => {
                 interval = new_dur;
-                continue;
+                deadline = Instant::now() + interval;
             }
-            Ok(ThrobberSignal::ChFrames(new_frames)) => {
+            ThrobberSignal::ChFrames(new_frames) => {
                 frames = new_frames;
                 frame = 0;
-                continue;
-            }
-            Ok(ThrobberSignal::End) => {
-                print!("\x1B[2K\r");
-                std::io::stdout().flush().unwrap();
-                break;
             }
-            Err(TryRecvError::Disconnected) => {
-                print!("\x1B[2K\r");
-                std::io::stdout().flush().unwrap();
+            ThrobberSignal::End => {
+                let mut stdout = io::stdout();
+                record(write!(stdout, "\x1B[2K\r"), &mut play_anim);
+                record(stdout.flush(), &mut play_anim);
                 break;
             }
-            Err(TryRecvError::Empty) => {
-                if play_anim == false {
-                    thread::park();
-                    continue;
-                }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "Frames must contain at least one frame")]
+    fn frames_from_empty_vec_panics() {
+        let _ = Frames::from(Vec::<String>::new());
+    }
+
+    #[test]
+    fn signal_sent_mid_interval_is_handled_before_old_deadline() {
+        // a long interval, so that if a mid-interval signal were only
+        // processed once the current frame deadline expired, this test would
+        // take ~10s instead of finishing within the bound asserted below
+        let mut throbber = Throbber::new("before", Duration::from_secs(10), &DEFAULT_F);
+        throbber.start();
+        thread::sleep(Duration::from_millis(20));
+
+        let before = Instant::now();
+        throbber.set_message("after");
+        throbber.set_interval(Duration::from_millis(5));
+        throbber.finish();
+        // dropping joins the worker, which returns only once it has handled
+        // every signal above plus the final End
+        drop(throbber);
+        let elapsed = before.elapsed();
+
+        assert!(
+            elapsed < Duration::from_secs(1),
+            "signals sent mid-interval took {:?} to take effect, expected \
+             them to be picked up well before the original 10s deadline",
+            elapsed
+        );
+    }
+
+    #[test]
+    fn methods_after_worker_exit_do_not_panic() {
+        let mut throbber = Throbber::default();
+        throbber.start();
+
+        // end the worker without going through Drop, so `anim` (and its
+        // sender) is still populated but the receiver it talks to is gone
+        if let Some(anim) = &throbber.anim {
+            let _ = anim.sender.send(ThrobberSignal::End);
+            while !anim.thread.is_finished() {
+                thread::yield_now();
             }
         }
-        print!("\x1B[2K\r");
-        print!("{} {}", frames[frame], msg);
-        std::io::stdout().flush().unwrap();
-        thread::sleep(interval);
-        frame = (frame + 1) % frames.len();
+
+        // every one of these used to panic via `.send(...).unwrap()` once the
+        // worker's receiver was dropped; they must now be no-ops instead
+        throbber.set_message("hi");
+        throbber.set_interval(Duration::from_millis(10));
+        throbber.set_frames(&DEFAULT_F);
+        throbber.start();
+        throbber.finish();
+        throbber.success("done");
+
+        // the guard's Drop impl calls finish() the same way; it must not
+        // panic either, which was the concrete bug (a dead worker turned
+        // ThrobberGuard's panic-proofing itself into a panic)
+        let guard = throbber.start_guard();
+        drop(guard);
+    }
+
+    #[test]
+    fn frames_by_name_known() {
+        assert!(Frames::by_name("dots12").is_some());
+        assert_eq!(Frames::by_name("default").unwrap().len(), DEFAULT_F.len());
+    }
+
+    #[test]
+    fn frames_by_name_unknown() {
+        assert!(Frames::by_name("not_a_real_animation").is_none());
+    }
+
+    #[test]
+    fn guard_drop_without_success_or_fail_does_not_hang() {
+        let mut throbber = Throbber::default();
+        {
+            let _guard = throbber.start_guard();
+            // dropped here without calling success/fail
+        }
+        // finish() was sent to the worker instead of leaving it spinning;
+        // dropping the throbber itself must not hang or panic
+        drop(throbber);
+    }
+
+    #[test]
+    fn stash_first_error_keeps_first() {
+        let slot = Mutex::new(None);
+        stash_first_error(&slot, io::Error::other("first"));
+        stash_first_error(&slot, io::Error::other("second"));
+        assert_eq!(slot.lock().unwrap().as_ref().unwrap().to_string(), "first");
     }
 }